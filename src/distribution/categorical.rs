@@ -0,0 +1,267 @@
+use rand::Rng;
+use distribution::{CheckedInverseCDF, Continuous, Discrete, Distribution, InverseCDF, Univariate};
+use statistics::{Max, Min};
+use error::StatsError;
+use Result;
+
+/// Implements the [Categorical](https://en.wikipedia.org/wiki/Categorical_distribution)
+/// distribution, also known as the generalized Bernoulli or discrete
+/// distribution, over the outcomes `{0, 1, ..., k - 1}`.
+///
+/// Sampling is performed in `O(1)` via the
+/// [Walker alias method](https://en.wikipedia.org/wiki/Alias_method),
+/// a table precomputed once in `new` from the supplied probabilities.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Categorical, Discrete};
+/// use statrs::prec;
+///
+/// let n = Categorical::new(&[0.0, 1.0, 2.0]).unwrap();
+/// assert!(prec::almost_eq(n.pmf(1.0), 1.0 / 3.0, 1e-15));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Categorical {
+    norm_pmf: Vec<f64>,
+    cdf: Vec<f64>,
+    alias_prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl Categorical {
+    /// Constructs a new categorical distribution over `prob.len()`
+    /// outcomes with relative weights given by `prob`. The weights do
+    /// not need to sum to `1.0`; they are normalized internally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prob` is empty, contains a negative, infinite,
+    /// or `NaN` entry, or sums to `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Categorical;
+    ///
+    /// let result = Categorical::new(&[0.0, 1.0, 2.0]);
+    /// assert!(result.is_ok());
+    ///
+    /// let result = Categorical::new(&[0.0, -1.0]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(prob: &[f64]) -> Result<Categorical> {
+        if prob.is_empty() || prob.iter().any(|&p| !p.is_finite() || p < 0.0) {
+            return Err(StatsError::BadParams);
+        }
+        let sum = prob.iter().fold(0.0, |acc, &p| acc + p);
+        if sum <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+
+        let norm_pmf: Vec<f64> = prob.iter().map(|&p| p / sum).collect();
+
+        let mut cdf = Vec::with_capacity(norm_pmf.len());
+        let mut acc = 0.0;
+        for &p in &norm_pmf {
+            acc += p;
+            cdf.push(acc);
+        }
+
+        let (alias_prob, alias) = build_alias_table(&norm_pmf);
+
+        Ok(Categorical {
+            norm_pmf: norm_pmf,
+            cdf: cdf,
+            alias_prob: alias_prob,
+            alias: alias,
+        })
+    }
+}
+
+// constructs the Walker alias table for the given (already normalized)
+// probability mass function, following Vose's linear-time variant:
+// scale each probability by `n`, partition into `small` (< 1) and `large`
+// (>= 1) worklists, then repeatedly pair off a small and a large entry,
+// donating the large entry's excess probability to cover the small one
+fn build_alias_table(norm_pmf: &[f64]) -> (Vec<f64>, Vec<usize>) {
+    let n = norm_pmf.len();
+    let mut scaled: Vec<f64> = norm_pmf.iter().map(|&p| p * n as f64).collect();
+    let mut prob = vec![0.0; n];
+    let mut alias = vec![0usize; n];
+
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+    for (i, &s) in scaled.iter().enumerate() {
+        if s < 1.0 {
+            small.push(i);
+        } else {
+            large.push(i);
+        }
+    }
+
+    while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+        prob[s] = scaled[s];
+        alias[s] = l;
+        scaled[l] -= 1.0 - scaled[s];
+        if scaled[l] < 1.0 {
+            small.push(l);
+        } else {
+            large.push(l);
+        }
+    }
+
+    // leftover entries are the result of floating-point rounding; their
+    // probability mass is effectively `1.0`
+    for i in large {
+        prob[i] = 1.0;
+    }
+    for i in small {
+        prob[i] = 1.0;
+    }
+
+    (prob, alias)
+}
+
+impl Distribution<f64> for Categorical {
+    /// Draws a sample in `O(1)` using the precomputed alias table: pick a
+    /// uniform category index, then accept it or its alias based on a
+    /// second uniform draw.
+    fn sample<R: Rng>(&self, r: &mut R) -> f64 {
+        let n = self.norm_pmf.len();
+        let i = r.gen_range(0, n);
+        let u = r.next_f64();
+        if u < self.alias_prob[i] {
+            i as f64
+        } else {
+            self.alias[i] as f64
+        }
+    }
+}
+
+impl Min<f64> for Categorical {
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Categorical {
+    fn max(&self) -> f64 {
+        self.norm_pmf.len() as f64 - 1.0
+    }
+}
+
+impl Univariate<f64, f64> for Categorical {
+    /// Calculates the cumulative distribution function at `x`, where `x`
+    /// is rounded down to the nearest category index.
+    fn cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x >= self.cdf.len() as f64 - 1.0 {
+            1.0
+        } else {
+            self.cdf[x.floor() as usize]
+        }
+    }
+}
+
+impl InverseCDF<f64> for Categorical {
+    /// Returns the smallest category index whose cumulative probability
+    /// is greater than or equal to `x`.
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.checked_inverse_cdf(x).unwrap()
+    }
+}
+
+impl CheckedInverseCDF<f64> for Categorical {
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if x < 0.0 || x > 1.0 {
+            return Err(StatsError::BadParams);
+        }
+        match self.cdf
+            .iter()
+            .position(|&p| x <= p) {
+            Some(i) => Ok(i as f64),
+            None => Ok(self.norm_pmf.len() as f64 - 1.0),
+        }
+    }
+}
+
+impl Discrete<f64, f64> for Categorical {
+    /// Returns the probability mass function evaluated at the category
+    /// index `x`, or `0.0` if `x` is out of range.
+    fn pmf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.fract() != 0.0 || x as usize >= self.norm_pmf.len() {
+            0.0
+        } else {
+            self.norm_pmf[x as usize]
+        }
+    }
+
+    fn ln_pmf(&self, x: f64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::StdRng;
+    use prec;
+
+    #[test]
+    fn test_new_rejects_invalid_params() {
+        assert!(Categorical::new(&[]).is_err());
+        assert!(Categorical::new(&[0.0, -1.0]).is_err());
+        assert!(Categorical::new(&[0.0, 0.0]).is_err());
+        assert!(Categorical::new(&[1.0, f64::NAN]).is_err());
+        assert!(Categorical::new(&[1.0, f64::INFINITY]).is_err());
+    }
+
+    #[test]
+    fn test_pmf_matches_normalized_weights() {
+        let n = Categorical::new(&[0.0, 1.0, 2.0]).unwrap();
+        assert!(prec::almost_eq(n.pmf(0.0), 0.0, 1e-15));
+        assert!(prec::almost_eq(n.pmf(1.0), 1.0 / 3.0, 1e-15));
+        assert!(prec::almost_eq(n.pmf(2.0), 2.0 / 3.0, 1e-15));
+        assert_eq!(n.pmf(3.0), 0.0);
+        assert_eq!(n.pmf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_cdf_and_inverse_cdf_roundtrip() {
+        let n = Categorical::new(&[1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert!(prec::almost_eq(n.cdf(0.0), 0.25, 1e-15));
+        assert!(prec::almost_eq(n.cdf(1.0), 0.5, 1e-15));
+        assert_eq!(n.cdf(10.0), 1.0);
+        assert_eq!(n.cdf(-1.0), 0.0);
+        assert_eq!(n.inverse_cdf(0.26), 1.0);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_alias_table_sampling_matches_pmf() {
+        // draw many samples and check the empirical frequencies roughly
+        // match the pmf, exercising the alias table built in `new`
+        let n = Categorical::new(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        let mut r = StdRng::new().unwrap();
+        let draws = 20_000;
+        let mut counts = [0u32; 4];
+        for _ in 0..draws {
+            let x = n.sample(&mut r);
+            counts[x as usize] += 1;
+        }
+        for i in 0..4 {
+            let empirical = counts[i] as f64 / draws as f64;
+            assert!((empirical - n.pmf(i as f64)).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn test_min_and_max() {
+        let n = Categorical::new(&[1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), 2.0);
+    }
+}
@@ -0,0 +1,270 @@
+use distribution::{Beta, Dirichlet, Gamma, Normal};
+use error::StatsError;
+use Result;
+
+/// The `ConjugatePrior` trait specifies an interface for Bayesian updating:
+/// combining a prior distribution with observed data to produce a posterior
+/// distribution of type `P`, without ever leaving the prior's distribution
+/// family.
+pub trait ConjugatePrior<X, P = Self> {
+    /// Returns the posterior distribution given the prior (`self`) and the
+    /// observed `data`.
+    fn posterior(&self, data: &[X]) -> P;
+}
+
+/// A `Beta` prior is conjugate to a `Bernoulli` likelihood: each observation
+/// is a single trial (`true` for a success), and the posterior is
+/// `Beta(alpha + successes, beta + failures)`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Beta, ConjugatePrior};
+///
+/// let prior = Beta::new(1.0, 1.0).unwrap();
+/// let posterior = prior.posterior(&[true, true, false]);
+/// assert_eq!(posterior.shape_a(), 3.0);
+/// assert_eq!(posterior.shape_b(), 2.0);
+/// ```
+impl ConjugatePrior<bool> for Beta {
+    fn posterior(&self, data: &[bool]) -> Beta {
+        let successes = data.iter().filter(|&&x| x).count() as f64;
+        let failures = data.len() as f64 - successes;
+        Beta::new(self.shape_a() + successes, self.shape_b() + failures)
+            .expect("posterior parameters of a valid Beta prior are always valid")
+    }
+}
+
+/// A `Beta` prior is conjugate to a `Binomial` likelihood: each observation
+/// is a `(successes, trials)` pair, and the posterior is
+/// `Beta(alpha + sum(successes), beta + sum(trials - successes))`.
+impl ConjugatePrior<(u64, u64)> for Beta {
+    fn posterior(&self, data: &[(u64, u64)]) -> Beta {
+        let (successes, trials) = data.iter()
+            .fold((0u64, 0u64), |(s, n), &(ds, dn)| (s + ds, n + dn));
+        let failures = trials - successes;
+        Beta::new(self.shape_a() + successes as f64, self.shape_b() + failures as f64)
+            .expect("posterior parameters of a valid Beta prior are always valid")
+    }
+}
+
+/// A `Gamma` prior is conjugate to a `Poisson` likelihood: each observation
+/// is an observed count, and the posterior is
+/// `Gamma(alpha + sum(x), beta + n)`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{ConjugatePrior, Gamma};
+///
+/// let prior = Gamma::new(2.0, 1.0).unwrap();
+/// let posterior = prior.posterior(&[3.0, 4.0, 5.0]);
+/// assert_eq!(posterior.shape(), 14.0);
+/// assert_eq!(posterior.rate(), 4.0);
+/// ```
+impl ConjugatePrior<f64> for Gamma {
+    fn posterior(&self, data: &[f64]) -> Gamma {
+        let sum = data.iter().fold(0.0, |acc, &x| acc + x);
+        Gamma::new(self.shape() + sum, self.rate() + data.len() as f64)
+            .expect("posterior parameters of a valid Gamma prior are always valid")
+    }
+}
+
+/// A `Dirichlet` prior is conjugate to a `Categorical` likelihood: each
+/// observation is the index of the observed category, and the posterior
+/// adds one pseudo-observation to the corresponding concentration
+/// parameter per occurrence.
+///
+/// # Panics
+///
+/// Panics if any observation in `data` is out of range for the prior,
+/// i.e. greater than or equal to `self.alpha().len()`.
+impl ConjugatePrior<usize> for Dirichlet {
+    fn posterior(&self, data: &[usize]) -> Dirichlet {
+        let mut alpha: Vec<f64> = self.alpha().to_vec();
+        for &category in data {
+            assert!(category < alpha.len(),
+                    "category {} is out of range for a Dirichlet prior over {} categories",
+                    category,
+                    alpha.len());
+            alpha[category] += 1.0;
+        }
+        Dirichlet::new(&alpha).expect("posterior parameters of a valid Dirichlet prior are always valid")
+    }
+}
+
+/// A `Dirichlet` prior is conjugate to a `Multinomial` likelihood: each
+/// observation is a vector of per-category counts, and the posterior adds
+/// the accumulated counts to the concentration parameters.
+///
+/// # Panics
+///
+/// Panics if any observation in `data` does not have exactly
+/// `self.alpha().len()` entries.
+impl ConjugatePrior<Vec<u64>> for Dirichlet {
+    fn posterior(&self, data: &[Vec<u64>]) -> Dirichlet {
+        let mut alpha: Vec<f64> = self.alpha().to_vec();
+        for counts in data {
+            assert_eq!(counts.len(),
+                       alpha.len(),
+                       "observation has {} categories, but the Dirichlet prior has {}",
+                       counts.len(),
+                       alpha.len());
+            for (a, &c) in alpha.iter_mut().zip(counts.iter()) {
+                *a += c as f64;
+            }
+        }
+        Dirichlet::new(&alpha).expect("posterior parameters of a valid Dirichlet prior are always valid")
+    }
+}
+
+/// A conjugate prior over the mean of a `Normal` likelihood with known
+/// variance. The posterior is also normally distributed, combining the
+/// prior's belief about the mean with the precision of the observed data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalMeanPrior {
+    mean: f64,
+    variance: f64,
+    likelihood_variance: f64,
+}
+
+impl NormalMeanPrior {
+    /// Constructs a new prior over the mean of a `Normal` likelihood whose
+    /// variance, `likelihood_variance`, is known in advance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `variance` or `likelihood_variance` are not
+    /// positive and finite.
+    pub fn new(mean: f64, variance: f64, likelihood_variance: f64) -> Result<NormalMeanPrior> {
+        if !variance.is_finite() || variance <= 0.0 || !likelihood_variance.is_finite() ||
+           likelihood_variance <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Ok(NormalMeanPrior {
+            mean: mean,
+            variance: variance,
+            likelihood_variance: likelihood_variance,
+        })
+    }
+}
+
+/// The posterior over the mean, given observations drawn from a `Normal`
+/// likelihood with the prior's known variance, is itself a `Normal`
+/// distribution.
+impl ConjugatePrior<f64, Normal> for NormalMeanPrior {
+    fn posterior(&self, data: &[f64]) -> Normal {
+        let n = data.len() as f64;
+        let sum = data.iter().fold(0.0, |acc, &x| acc + x);
+        let prior_precision = 1.0 / self.variance;
+        let data_precision = n / self.likelihood_variance;
+        let posterior_precision = prior_precision + data_precision;
+        // expressed as a sum rather than `data_precision * (sum / n)` so
+        // an empty `data` slice (n == 0, sum == 0.0) naturally reduces to
+        // the prior instead of dividing `0.0` by `0.0`
+        let posterior_mean = (prior_precision * self.mean + sum / self.likelihood_variance) /
+                              posterior_precision;
+        Normal::new(posterior_mean, (1.0 / posterior_precision).sqrt())
+            .expect("posterior parameters of a valid NormalMeanPrior are always valid")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use distribution::Dirichlet;
+    use prec;
+
+    #[test]
+    fn test_beta_bernoulli_posterior() {
+        let prior = Beta::new(1.0, 1.0).unwrap();
+        let posterior = prior.posterior(&[true, true, false]);
+        assert_eq!(posterior.shape_a(), 3.0);
+        assert_eq!(posterior.shape_b(), 2.0);
+    }
+
+    #[test]
+    fn test_beta_bernoulli_posterior_empty_data() {
+        let prior = Beta::new(2.0, 3.0).unwrap();
+        let posterior = prior.posterior(&[]);
+        assert_eq!(posterior.shape_a(), prior.shape_a());
+        assert_eq!(posterior.shape_b(), prior.shape_b());
+    }
+
+    #[test]
+    fn test_beta_binomial_posterior() {
+        let prior = Beta::new(1.0, 1.0).unwrap();
+        let posterior = prior.posterior(&[(3u64, 5u64), (2u64, 5u64)]);
+        assert_eq!(posterior.shape_a(), 6.0);
+        assert_eq!(posterior.shape_b(), 6.0);
+    }
+
+    #[test]
+    fn test_gamma_poisson_posterior() {
+        let prior = Gamma::new(2.0, 1.0).unwrap();
+        let posterior = prior.posterior(&[3.0, 4.0, 5.0]);
+        assert_eq!(posterior.shape(), 14.0);
+        assert_eq!(posterior.rate(), 4.0);
+    }
+
+    #[test]
+    fn test_gamma_poisson_posterior_empty_data() {
+        let prior = Gamma::new(2.0, 1.0).unwrap();
+        let posterior = prior.posterior(&[]);
+        assert_eq!(posterior.shape(), prior.shape());
+        assert_eq!(posterior.rate(), prior.rate());
+    }
+
+    #[test]
+    fn test_dirichlet_categorical_posterior() {
+        let prior = Dirichlet::new(&[1.0, 1.0, 1.0]).unwrap();
+        let posterior = prior.posterior(&[0usize, 0usize, 2usize]);
+        assert_eq!(posterior.alpha(), &[3.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_dirichlet_multinomial_posterior() {
+        let prior = Dirichlet::new(&[1.0, 1.0]).unwrap();
+        let posterior = prior.posterior(&[vec![2u64, 1u64], vec![0u64, 3u64]]);
+        assert_eq!(posterior.alpha(), &[3.0, 5.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dirichlet_categorical_posterior_rejects_out_of_range_category() {
+        let prior = Dirichlet::new(&[1.0, 1.0, 1.0]).unwrap();
+        prior.posterior(&[0usize, 3usize]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dirichlet_multinomial_posterior_rejects_mismatched_length() {
+        let prior = Dirichlet::new(&[1.0, 1.0]).unwrap();
+        prior.posterior(&[vec![1u64, 1u64, 1u64]]);
+    }
+
+    #[test]
+    fn test_normal_mean_prior_posterior() {
+        let prior = NormalMeanPrior::new(0.0, 1.0, 1.0).unwrap();
+        let posterior = prior.posterior(&[2.0, 2.0, 2.0, 2.0]);
+        // precision-weighted average of prior mean 0.0 (precision 1.0) and
+        // sample mean 2.0 (precision 4.0) is 8.0 / 5.0
+        assert!(prec::almost_eq(posterior.mean(), 1.6, 1e-12));
+    }
+
+    #[test]
+    fn test_normal_mean_prior_posterior_empty_data_reduces_to_prior() {
+        let prior = NormalMeanPrior::new(3.0, 2.0, 5.0).unwrap();
+        let posterior = prior.posterior(&[]);
+        assert!(posterior.mean().is_finite());
+        assert!(prec::almost_eq(posterior.mean(), 3.0, 1e-12));
+        assert!(prec::almost_eq(posterior.std_dev(), 2.0f64.sqrt(), 1e-12));
+    }
+
+    #[test]
+    fn test_normal_mean_prior_new_rejects_invalid_variance() {
+        assert!(NormalMeanPrior::new(0.0, 0.0, 1.0).is_err());
+        assert!(NormalMeanPrior::new(0.0, 1.0, -1.0).is_err());
+        assert!(NormalMeanPrior::new(0.0, f64::NAN, 1.0).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use distribution::Continuous;
+use statistics::Min;
+use prec;
+
+// maximum recursion depth for the adaptive Simpson integrator below, chosen
+// to bound worst-case work on pathological (e.g. highly multimodal)
+// integrands while still reaching machine precision on well-behaved ones
+const MAX_DEPTH: u32 = 50;
+
+// negligible density threshold and iteration cap used by `widen_bound` below
+const TAIL_EPSILON: f64 = 1e-12;
+const MAX_WIDEN_ITERATIONS: u32 = 200;
+
+fn simpson<F: Fn(f64) -> f64>(f: &F, a: f64, b: f64) -> f64 {
+    (b - a) / 6.0 * (f(a) + 4.0 * f((a + b) / 2.0) + f(b))
+}
+
+fn adaptive_simpson<F: Fn(f64) -> f64>(f: &F,
+                                        a: f64,
+                                        b: f64,
+                                        eps: f64,
+                                        whole: f64,
+                                        depth: u32)
+                                        -> f64 {
+    let m = (a + b) / 2.0;
+    let left = simpson(f, a, m);
+    let right = simpson(f, m, b);
+    if depth == 0 || (left + right - whole).abs() < 15.0 * eps {
+        left + right + (left + right - whole) / 15.0
+    } else {
+        adaptive_simpson(f, a, m, eps / 2.0, left, depth - 1) +
+        adaptive_simpson(f, m, b, eps / 2.0, right, depth - 1)
+    }
+}
+
+/// Integrates `f` over `[a, b]` via adaptive Simpson's rule, recursively
+/// refining the quadrature until successive estimates agree within `eps`
+/// or `MAX_DEPTH` is reached. Unlike fixed-grid quadrature, this
+/// concentrates evaluations where `f` varies quickly, so it correctly
+/// handles multimodal integrands.
+pub fn integrate<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, eps: f64) -> f64 {
+    let whole = simpson(&f, a, b);
+    adaptive_simpson(&f, a, b, eps, whole, MAX_DEPTH)
+}
+
+/// Finds a finite bound suitable for replacing an unbounded integration
+/// limit, by walking away from `x` in the given `direction` (`-1.0` for a
+/// lower limit, `1.0` for an upper limit) over a sequence of contiguous,
+/// geometrically growing segments, until a segment is found whose
+/// integrated contribution is negligible *after* some earlier segment has
+/// already turned up non-negligible mass. This adapts the cutoff to the
+/// distribution's own location and scale (e.g. a `Normal` far from `0`)
+/// rather than relying on a fixed absolute cutoff, which would silently
+/// miss the distribution's mass.
+///
+/// Integrating each segment (rather than sampling the density at its single
+/// endpoint) is what makes this correct even when `x` itself lands in a
+/// negligible-density region far from the distribution's bulk: the bulk can
+/// then only be skipped over if it falls entirely inside the gap between
+/// sampled points, which contiguous segments rule out by construction.
+pub fn widen_bound<F: Fn(f64) -> f64>(pdf: &F, x: f64, direction: f64) -> f64 {
+    let mut bound = x;
+    let mut step = 1.0;
+    let mut seen_mass = false;
+    for _ in 0..MAX_WIDEN_ITERATIONS {
+        let next = bound + direction * step;
+        let (lo, hi) = if direction < 0.0 { (next, bound) } else { (bound, next) };
+        let contribution = integrate(pdf, lo, hi, 1e-8).abs();
+        bound = next;
+        if contribution > TAIL_EPSILON {
+            seen_mass = true;
+        } else if seen_mass {
+            break;
+        }
+        step *= 2.0;
+    }
+    bound
+}
+
+/// Computes a numerical cumulative distribution function for `dist` at `x`
+/// by integrating its `pdf` from `dist.min()` (or, if unbounded, a lower
+/// limit found via `widen_bound`) to `x`. Intended as a fallback for
+/// `Continuous` implementors that have no closed-form `cdf`.
+pub fn cdf_from_pdf<D: Continuous<f64, f64> + Min<f64>>(dist: &D, x: f64) -> f64 {
+    let lower = dist.min();
+    let a = if lower.is_infinite() {
+        widen_bound(&|t| dist.pdf(t), x, -1.0)
+    } else {
+        lower
+    };
+    if x <= a {
+        return 0.0;
+    }
+    // integrate over contiguous, geometrically growing segments walking
+    // down from `x`, as `widen_bound` does, so that a bulk of mass lying
+    // far from `x` is not skipped by a single coarse Simpson estimate
+    let mut total = 0.0;
+    let mut hi = x;
+    let mut step = 1.0;
+    while hi > a {
+        let lo = (hi - step).max(a);
+        total += integrate(|t| dist.pdf(t), lo, hi, 1e-10);
+        hi = lo;
+        step *= 2.0;
+    }
+    total
+}
+
+// maximum number of Aitken iterations before giving up and returning the
+// latest accelerated estimate
+const ACCELERATION_MAX_ITERATIONS: u32 = 1000;
+
+/// Applies Aitken's delta-squared process to accelerate the convergence of
+/// a sequence of partial sums produced by repeatedly calling `next`, as used
+/// by distributions whose cdf or moments are computed as an infinite series
+/// (e.g. discrete tail probabilities). Iterates until successive
+/// accelerated estimates agree within `prec::F64_PREC`, falling back to the
+/// latest partial sum if the denominator underflows.
+pub fn accelerate<F: FnMut() -> f64>(mut next: F) -> f64 {
+    let mut x0 = next();
+    let mut x1 = next();
+    let mut x2 = next();
+    let mut accelerated = x0;
+
+    for _ in 0..ACCELERATION_MAX_ITERATIONS {
+        let denom = x2 - 2.0 * x1 + x0;
+        if denom.abs() < ::std::f64::EPSILON {
+            return x2;
+        }
+        let next_accelerated = x0 - (x1 - x0) * (x1 - x0) / denom;
+        if prec::almost_eq(next_accelerated, accelerated, prec::F64_PREC) {
+            return next_accelerated;
+        }
+        accelerated = next_accelerated;
+        x0 = x1;
+        x1 = x2;
+        x2 = next();
+    }
+    accelerated
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use distribution::Normal;
+    use prec;
+
+    #[test]
+    fn test_integrate_standard_normal_pdf_over_full_support() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        let total = integrate(|t| n.pdf(t), -10.0, 10.0, 1e-12);
+        assert!(prec::almost_eq(total, 1.0, 1e-8));
+    }
+
+    #[test]
+    fn test_widen_bound_adapts_to_location_and_scale() {
+        let n = Normal::new(-1_000_000.0, 1.0).unwrap();
+        let bound = widen_bound(&|t| n.pdf(t), -1_000_000.0, -1.0);
+        // the bound should land near the distribution's own mean, not near
+        // a small fixed absolute cutoff such as -1e4
+        assert!(bound < -1_000_000.0 - 3.0 && bound > -1_000_000.0 - 1000.0);
+    }
+
+    #[test]
+    fn test_cdf_from_pdf_matches_known_cdf_near_mean() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(prec::almost_eq(cdf_from_pdf(&n, 0.0), 0.5, 1e-6));
+    }
+
+    #[test]
+    fn test_cdf_from_pdf_handles_distant_mean() {
+        // regression test: a fixed absolute cutoff (e.g. -1e4) would make
+        // this incorrectly report a cdf of 0.0 at the distribution's own
+        // mean instead of ~0.5
+        let n = Normal::new(-1_000_000.0, 1.0).unwrap();
+        assert!(prec::almost_eq(cdf_from_pdf(&n, -1_000_000.0), 0.5, 1e-4));
+    }
+
+    #[test]
+    fn test_cdf_from_pdf_handles_point_past_the_tail() {
+        // regression test: querying at a point whose own density is already
+        // negligible (deep in the tail, not just far from 0) used to make
+        // `widen_bound` stop on the very first step, since it only checked
+        // the density immediately adjacent to `x` rather than confirming no
+        // mass lay further out; this previously reported a cdf of ~0.0
+        // instead of the correct ~1.0
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(prec::almost_eq(cdf_from_pdf(&n, 50.0), 1.0, 1e-6));
+    }
+
+    #[test]
+    fn test_accelerate_converges_on_geometric_series() {
+        // partial sums of sum(0.5^k) for k=0.. converge to 2.0, but slowly;
+        // Aitken's process should reach the limit in far fewer terms
+        let mut term = 1.0;
+        let mut sum = 0.0;
+        let result = accelerate(|| {
+            sum += term;
+            term *= 0.5;
+            sum
+        });
+        assert!(prec::almost_eq(result, 2.0, 1e-8));
+    }
+
+    #[test]
+    fn test_accelerate_falls_back_on_constant_sequence() {
+        // a constant sequence makes the Aitken denominator 0.0; accelerate
+        // should fall back to the latest partial sum instead of dividing
+        // by zero
+        let result = accelerate(|| 5.0);
+        assert_eq!(result, 5.0);
+    }
+}
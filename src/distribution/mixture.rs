@@ -0,0 +1,232 @@
+use rand::Rng;
+use distribution::{Continuous, Discrete, Distribution, Univariate};
+use statistics::{Max, Min};
+use error::StatsError;
+use Result;
+
+/// Implements the [Mixture](https://en.wikipedia.org/wiki/Mixture_distribution)
+/// distribution, a weighted combination of component distributions of the
+/// same type, e.g. a Gaussian mixture built from several `Normal`
+/// components. All components share the type `D`, so components of
+/// different distribution types cannot be mixed. `pmf`/`ln_pmf` are
+/// provided only for components implementing `Discrete<f64, f64>` (such
+/// as `Categorical`); count distributions whose `pmf` takes a `u64`, such
+/// as `Poisson`, get no `pmf` through `Mixture`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, Mixture, Normal};
+///
+/// let n = Mixture::new(vec![Normal::new(0.0, 1.0).unwrap(), Normal::new(5.0, 1.0).unwrap()],
+///                       vec![0.5, 0.5])
+///     .unwrap();
+/// assert!(n.pdf(0.0) > 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mixture<D> {
+    components: Vec<D>,
+    weights: Vec<f64>,
+}
+
+impl<D> Mixture<D> {
+    /// Constructs a new mixture distribution from the given component
+    /// distributions and weights. The weights do not need to sum to `1.0`;
+    /// they are normalized internally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `components` and `weights` differ in length,
+    /// either is empty, or any weight is negative, infinite, or `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Mixture, Normal};
+    ///
+    /// let components = vec![Normal::new(0.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()];
+    /// let result = Mixture::new(components, vec![1.0, 3.0]);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn new(components: Vec<D>, weights: Vec<f64>) -> Result<Mixture<D>> {
+        if components.is_empty() || components.len() != weights.len() {
+            return Err(StatsError::BadParams);
+        }
+        if weights.iter().any(|&w| !w.is_finite() || w < 0.0) {
+            return Err(StatsError::BadParams);
+        }
+        let sum = weights.iter().fold(0.0, |acc, &w| acc + w);
+        if sum <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let normalized = weights.iter().map(|&w| w / sum).collect();
+        Ok(Mixture {
+            components: components,
+            weights: normalized,
+        })
+    }
+
+    /// Returns the component distributions of the mixture.
+    pub fn components(&self) -> &[D] {
+        &self.components
+    }
+
+    /// Returns the normalized weights of the mixture.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+impl<D> Distribution<f64> for Mixture<D>
+    where D: Distribution<f64>
+{
+    fn sample<R: Rng>(&self, r: &mut R) -> f64 {
+        let mut u = r.next_f64();
+        for (c, &w) in self.components.iter().zip(self.weights.iter()) {
+            if u < w {
+                return c.sample(r);
+            }
+            u -= w;
+        }
+        self.components.last().unwrap().sample(r)
+    }
+}
+
+impl<D> Min<f64> for Mixture<D>
+    where D: Min<f64>
+{
+    fn min(&self) -> f64 {
+        self.components
+            .iter()
+            .fold(f64::INFINITY, |acc, c| acc.min(c.min()))
+    }
+}
+
+impl<D> Max<f64> for Mixture<D>
+    where D: Max<f64>
+{
+    fn max(&self) -> f64 {
+        self.components
+            .iter()
+            .fold(f64::NEG_INFINITY, |acc, c| acc.max(c.max()))
+    }
+}
+
+impl<D> Univariate<f64, f64> for Mixture<D>
+    where D: Univariate<f64, f64>
+{
+    fn cdf(&self, x: f64) -> f64 {
+        self.components
+            .iter()
+            .zip(self.weights.iter())
+            .fold(0.0, |acc, (c, &w)| acc + w * c.cdf(x))
+    }
+}
+
+impl<D> Continuous<f64, f64> for Mixture<D>
+    where D: Continuous<f64, f64>
+{
+    fn pdf(&self, x: f64) -> f64 {
+        self.components
+            .iter()
+            .zip(self.weights.iter())
+            .fold(0.0, |acc, (c, &w)| acc + w * c.pdf(x))
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        log_sum_exp(self.components.iter().zip(self.weights.iter()).map(|(c, &w)| w.ln() + c.ln_pdf(x)))
+    }
+}
+
+impl<D> Discrete<f64, f64> for Mixture<D>
+    where D: Discrete<f64, f64>
+{
+    fn pmf(&self, x: f64) -> f64 {
+        self.components
+            .iter()
+            .zip(self.weights.iter())
+            .fold(0.0, |acc, (c, &w)| acc + w * c.pmf(x))
+    }
+
+    fn ln_pmf(&self, x: f64) -> f64 {
+        log_sum_exp(self.components.iter().zip(self.weights.iter()).map(|(c, &w)| w.ln() + c.ln_pmf(x)))
+    }
+}
+
+// computes ln(sum(exp(x))) for the given terms in a numerically stable way
+fn log_sum_exp<I: Iterator<Item = f64>>(terms: I) -> f64 {
+    let ln_terms: Vec<f64> = terms.collect();
+    let max = ln_terms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if max.is_infinite() {
+        return max;
+    }
+    max + ln_terms.iter().fold(0.0, |acc, &t| acc + (t - max).exp()).ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use distribution::Normal;
+    use prec;
+
+    #[test]
+    fn test_new_rejects_mismatched_lengths() {
+        let components = vec![Normal::new(0.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()];
+        assert!(Mixture::new(components, vec![1.0]).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_empty_components() {
+        let components: Vec<Normal> = vec![];
+        assert!(Mixture::new(components, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_new_rejects_negative_or_nonfinite_weight() {
+        let components = vec![Normal::new(0.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()];
+        assert!(Mixture::new(components.clone(), vec![1.0, -1.0]).is_err());
+        assert!(Mixture::new(components, vec![1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_new_normalizes_weights() {
+        let components = vec![Normal::new(0.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()];
+        let m = Mixture::new(components, vec![1.0, 3.0]).unwrap();
+        assert_eq!(m.weights(), &[0.25, 0.75]);
+    }
+
+    #[test]
+    fn test_pdf_matches_weighted_sum() {
+        let a = Normal::new(0.0, 1.0).unwrap();
+        let b = Normal::new(5.0, 1.0).unwrap();
+        let m = Mixture::new(vec![a, b], vec![0.5, 0.5]).unwrap();
+        let expected = 0.5 * a.pdf(0.0) + 0.5 * b.pdf(0.0);
+        assert!(prec::almost_eq(m.pdf(0.0), expected, 1e-12));
+    }
+
+    #[test]
+    fn test_ln_pdf_matches_pdf() {
+        let a = Normal::new(0.0, 1.0).unwrap();
+        let b = Normal::new(5.0, 1.0).unwrap();
+        let m = Mixture::new(vec![a, b], vec![0.5, 0.5]).unwrap();
+        assert!(prec::almost_eq(m.ln_pdf(1.0), m.pdf(1.0).ln(), 1e-10));
+    }
+
+    #[test]
+    fn test_cdf_matches_weighted_sum() {
+        let a = Normal::new(0.0, 1.0).unwrap();
+        let b = Normal::new(5.0, 1.0).unwrap();
+        let m = Mixture::new(vec![a, b], vec![0.5, 0.5]).unwrap();
+        let expected = 0.5 * a.cdf(2.0) + 0.5 * b.cdf(2.0);
+        assert!(prec::almost_eq(m.cdf(2.0), expected, 1e-12));
+    }
+
+    #[test]
+    fn test_min_and_max_span_components() {
+        let a = Normal::new(0.0, 1.0).unwrap();
+        let b = Normal::new(5.0, 1.0).unwrap();
+        let m = Mixture::new(vec![a, b], vec![0.5, 0.5]).unwrap();
+        assert_eq!(m.min(), f64::NEG_INFINITY);
+        assert_eq!(m.max(), f64::INFINITY);
+    }
+}
@@ -0,0 +1,320 @@
+use distribution::{Bernoulli, Binomial, Beta, Exponential, Gamma, Geometric, Normal, Pareto,
+                    Poisson, Uniform};
+use error::StatsError;
+use function::gamma;
+use prec;
+use Result;
+
+/// The `MaximumLikelihood` trait specifies an interface for fitting a
+/// distribution's parameters to observed data via maximum likelihood
+/// estimation.
+pub trait MaximumLikelihood<T>: Sized {
+    /// Fits the parameters of `Self` that maximize the likelihood of
+    /// the supplied `data`, returning the fitted distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty or the resulting parameters
+    /// are invalid for the distribution (e.g. zero variance).
+    fn fit(data: &[T]) -> Result<Self>;
+}
+
+fn mean(data: &[f64]) -> f64 {
+    data.iter().fold(0.0, |acc, &x| acc + x) / data.len() as f64
+}
+
+fn biased_variance(data: &[f64], mean: f64) -> f64 {
+    data.iter().fold(0.0, |acc, &x| acc + (x - mean) * (x - mean)) / data.len() as f64
+}
+
+impl MaximumLikelihood<f64> for Normal {
+    /// Fits `mean` and `std_dev` as the sample mean and (biased) sample
+    /// standard deviation of `data`.
+    fn fit(data: &[f64]) -> Result<Normal> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let m = mean(data);
+        let var = biased_variance(data, m);
+        Normal::new(m, var.sqrt())
+    }
+}
+
+impl MaximumLikelihood<f64> for Exponential {
+    /// Fits `rate` as the reciprocal of the sample mean of `data`.
+    fn fit(data: &[f64]) -> Result<Exponential> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        Exponential::new(1.0 / mean(data))
+    }
+}
+
+impl MaximumLikelihood<f64> for Poisson {
+    /// Fits `lambda` as the sample mean of `data`.
+    fn fit(data: &[f64]) -> Result<Poisson> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        Poisson::new(mean(data))
+    }
+}
+
+impl MaximumLikelihood<f64> for Bernoulli {
+    /// Fits `p` as the frequency of non-zero observations in `data`.
+    fn fit(data: &[f64]) -> Result<Bernoulli> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let successes = data.iter().filter(|&&x| x != 0.0).count() as f64;
+        Bernoulli::new(successes / data.len() as f64)
+    }
+}
+
+impl MaximumLikelihood<(u64, u64)> for Binomial {
+    /// Fits `p` as the overall success frequency across a series of
+    /// `(successes, trials)` observations, all of which must share the
+    /// same `trials` count, used as `n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, every observation has `0`
+    /// trials, or the observations do not all share the same `trials`
+    /// count (there is no single well-defined `n` to fit in that case).
+    fn fit(data: &[(u64, u64)]) -> Result<Binomial> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let n = data[0].1;
+        if data.iter().any(|&(_, trials)| trials != n) {
+            return Err(StatsError::BadParams);
+        }
+        let total_successes = data.iter().fold(0u64, |acc, &(s, _)| acc + s);
+        let total_trials = data.iter().fold(0u64, |acc, &(_, n)| acc + n);
+        if total_trials == 0 {
+            return Err(StatsError::BadParams);
+        }
+        let p = total_successes as f64 / total_trials as f64;
+        Binomial::new(p, n)
+    }
+}
+
+impl MaximumLikelihood<f64> for Uniform {
+    /// Fits `min` and `max` as the sample minimum and maximum of `data`.
+    fn fit(data: &[f64]) -> Result<Uniform> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let min = data.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Uniform::new(min, max)
+    }
+}
+
+impl MaximumLikelihood<f64> for Pareto {
+    /// Fits `scale` as the sample minimum and `shape` as
+    /// `n / sum(ln(x_i / scale))`.
+    fn fit(data: &[f64]) -> Result<Pareto> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let scale = data.iter().cloned().fold(f64::INFINITY, f64::min);
+        if scale <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let sum_ln = data.iter().fold(0.0, |acc, &x| acc + (x / scale).ln());
+        if sum_ln <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Pareto::new(scale, data.len() as f64 / sum_ln)
+    }
+}
+
+impl MaximumLikelihood<f64> for Geometric {
+    /// Fits `p` as the reciprocal of the sample mean of `data`, where each
+    /// observation is the number of trials until (and including) the
+    /// first success.
+    fn fit(data: &[f64]) -> Result<Geometric> {
+        if data.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        Geometric::new(1.0 / mean(data))
+    }
+}
+
+// maximum number of Newton iterations to run when no closed form exists
+const MAX_ITERATIONS: u64 = 100;
+
+impl MaximumLikelihood<f64> for Gamma {
+    /// Fits `shape` and `rate` via Newton's method on the log-likelihood,
+    /// seeded with the method-of-moments estimate, since `Gamma` has no
+    /// closed-form maximum likelihood solution.
+    fn fit(data: &[f64]) -> Result<Gamma> {
+        if data.is_empty() || data.iter().any(|&x| !x.is_finite() || x <= 0.0) {
+            return Err(StatsError::BadParams);
+        }
+        let m = mean(data);
+        let var = biased_variance(data, m);
+        if m <= 0.0 || var <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let ln_mean = m.ln();
+        let mean_ln = data.iter().fold(0.0, |acc, &x| acc + x.ln()) / data.len() as f64;
+        let s = ln_mean - mean_ln;
+
+        // initial method-of-moments estimate for the shape
+        let mut shape = m * m / var;
+        for _ in 0..MAX_ITERATIONS {
+            let f = shape.ln() - gamma::digamma(shape) - s;
+            let df = 1.0 / shape - gamma::trigamma(shape);
+            let next = shape - f / df;
+            if !next.is_finite() || next <= 0.0 {
+                break;
+            }
+            if prec::almost_eq(next, shape, prec::F64_PREC) {
+                shape = next;
+                break;
+            }
+            shape = next;
+        }
+        Gamma::new(shape, shape / m)
+    }
+}
+
+impl MaximumLikelihood<f64> for Beta {
+    /// Fits `shape_a` and `shape_b` via fixed-point iteration on the
+    /// digamma-based likelihood equations, seeded with the
+    /// method-of-moments estimate, since `Beta` has no closed-form
+    /// maximum likelihood solution.
+    fn fit(data: &[f64]) -> Result<Beta> {
+        if data.is_empty() || data.iter().any(|&x| !x.is_finite() || x <= 0.0 || x >= 1.0) {
+            return Err(StatsError::BadParams);
+        }
+        let m = mean(data);
+        let var = biased_variance(data, m);
+        if m <= 0.0 || m >= 1.0 || var <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let common = m * (1.0 - m) / var - 1.0;
+        let mut a = m * common;
+        let mut b = (1.0 - m) * common;
+
+        let mean_ln_x = data.iter().fold(0.0, |acc, &x| acc + x.ln()) / data.len() as f64;
+        let mean_ln_1mx = data.iter().fold(0.0, |acc, &x| acc + (1.0 - x).ln()) / data.len() as f64;
+
+        for _ in 0..MAX_ITERATIONS {
+            let digamma_ab = gamma::digamma(a + b);
+            let next_a = inverse_digamma(digamma_ab + mean_ln_x);
+            let next_b = inverse_digamma(digamma_ab + mean_ln_1mx);
+            if !next_a.is_finite() || !next_b.is_finite() || next_a <= 0.0 || next_b <= 0.0 {
+                break;
+            }
+            let converged = prec::almost_eq(next_a, a, prec::F64_PREC) &&
+                             prec::almost_eq(next_b, b, prec::F64_PREC);
+            a = next_a;
+            b = next_b;
+            if converged {
+                break;
+            }
+        }
+        Beta::new(a, b)
+    }
+}
+
+// inverts the digamma function via Newton's method, used to refine the
+// Beta shape parameters above
+fn inverse_digamma(y: f64) -> f64 {
+    let mut x = if y >= -2.22 {
+        y.exp() + 0.5
+    } else {
+        -1.0 / (y - gamma::digamma(1.0))
+    };
+    for _ in 0..MAX_ITERATIONS {
+        let next = x - (gamma::digamma(x) - y) / gamma::trigamma(x);
+        if !next.is_finite() || next <= 0.0 {
+            break;
+        }
+        if prec::almost_eq(next, x, prec::F64_PREC) {
+            x = next;
+            break;
+        }
+        x = next;
+    }
+    x
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use prec;
+
+    #[test]
+    fn test_normal_fit() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let n = Normal::fit(&data).unwrap();
+        assert!(prec::almost_eq(n.mean(), 3.0, 1e-12));
+    }
+
+    #[test]
+    fn test_normal_fit_rejects_empty_data() {
+        let data: [f64; 0] = [];
+        assert!(Normal::fit(&data).is_err());
+    }
+
+    #[test]
+    fn test_exponential_fit() {
+        let data = [1.0, 1.0, 1.0, 1.0];
+        let r = Exponential::fit(&data).unwrap();
+        assert!(prec::almost_eq(r.rate(), 1.0, 1e-12));
+    }
+
+    #[test]
+    fn test_binomial_fit() {
+        let data = [(3u64, 10u64), (4u64, 10u64)];
+        let b = Binomial::fit(&data).unwrap();
+        assert!(prec::almost_eq(b.p(), 0.35, 1e-12));
+        assert_eq!(b.n(), 10);
+    }
+
+    #[test]
+    fn test_binomial_fit_rejects_mismatched_trial_counts() {
+        let data = [(3u64, 10u64), (4u64, 8u64)];
+        assert!(Binomial::fit(&data).is_err());
+    }
+
+    #[test]
+    fn test_uniform_fit() {
+        let data = [0.5, -1.0, 2.0, 1.5];
+        let u = Uniform::fit(&data).unwrap();
+        assert_eq!(u.min(), -1.0);
+        assert_eq!(u.max(), 2.0);
+    }
+
+    #[test]
+    fn test_gamma_fit_recovers_valid_parameters() {
+        // tightly clustered data (sample mean 2.914, biased variance about
+        // 0.104); the fit should return a positive shape and rate
+        let data = [2.31, 3.06, 2.57, 3.29, 2.78, 3.41, 2.66, 3.15, 2.89, 3.02];
+        let g = Gamma::fit(&data).unwrap();
+        assert!(g.shape() > 0.0 && g.rate() > 0.0);
+    }
+
+    #[test]
+    fn test_gamma_fit_rejects_nonpositive_observation() {
+        let data = [1.0, 2.0, 0.0, 3.0];
+        assert!(Gamma::fit(&data).is_err());
+    }
+
+    #[test]
+    fn test_beta_fit_recovers_valid_parameters() {
+        let data = [0.2, 0.4, 0.5, 0.6, 0.3, 0.45];
+        let b = Beta::fit(&data).unwrap();
+        assert!(b.shape_a() > 0.0 && b.shape_b() > 0.0);
+    }
+
+    #[test]
+    fn test_beta_fit_rejects_out_of_range_observation() {
+        let data = [0.2, 0.4, 1.5, 0.6];
+        assert!(Beta::fit(&data).is_err());
+    }
+}
@@ -11,6 +11,8 @@ pub use self::categorical::Categorical;
 pub use self::cauchy::Cauchy;
 pub use self::chi::Chi;
 pub use self::chi_squared::ChiSquared;
+pub use self::conjugate_prior::{ConjugatePrior, NormalMeanPrior};
+pub use self::convolution::{Convolution, DiscreteConvolution, convolve_discrete_pmf};
 pub use self::dirichlet::Dirichlet;
 pub use self::discrete_uniform::DiscreteUniform;
 pub use self::erlang::Erlang;
@@ -21,10 +23,13 @@ pub use self::geometric::Geometric;
 pub use self::hypergeometric::Hypergeometric;
 pub use self::inverse_gamma::InverseGamma;
 pub use self::log_normal::LogNormal;
+pub use self::mixture::Mixture;
+pub use self::mle::MaximumLikelihood;
 pub use self::multinomial::Multinomial;
 pub use self::normal::Normal;
 pub use self::pareto::Pareto;
 pub use self::poisson::Poisson;
+pub use self::stick_breaking::StickBreaking;
 pub use self::students_t::StudentsT;
 pub use self::triangular::Triangular;
 pub use self::uniform::Uniform;
@@ -38,6 +43,8 @@ mod categorical;
 mod cauchy;
 mod chi;
 mod chi_squared;
+mod conjugate_prior;
+mod convolution;
 mod dirichlet;
 mod discrete_uniform;
 mod erlang;
@@ -49,10 +56,13 @@ mod hypergeometric;
 mod internal;
 mod inverse_gamma;
 mod log_normal;
+mod mixture;
+mod mle;
 mod multinomial;
 mod normal;
 mod pareto;
 mod poisson;
+mod stick_breaking;
 mod students_t;
 mod triangular;
 mod uniform;
@@ -0,0 +1,157 @@
+use rand::Rng;
+use distribution::{Beta, Discrete, Distribution};
+use error::StatsError;
+use Result;
+
+/// Implements the stick-breaking construction of a
+/// [Dirichlet process](https://en.wikipedia.org/wiki/Dirichlet_process),
+/// generating an unbounded sequence of mixture weights from a single
+/// concentration parameter `alpha`: `beta_k ~ Beta(1, alpha)` and
+/// `w_k = beta_k * product_{j < k}(1 - beta_j)`.
+///
+/// Pair this with [`Mixture`](struct.Mixture.html) to build
+/// Dirichlet process mixture models on top of the existing distribution
+/// zoo.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::StickBreaking;
+///
+/// let sb = StickBreaking::new(1.0, 10).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StickBreaking {
+    alpha: f64,
+    truncation: u64,
+}
+
+impl StickBreaking {
+    /// Constructs a new stick-breaking process with concentration `alpha`,
+    /// truncated to `truncation` levels for the purposes of `pmf`/`ln_pmf`
+    /// (sampling is unaffected by the truncation level).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` is not positive and finite, or if
+    /// `truncation` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StickBreaking;
+    ///
+    /// let result = StickBreaking::new(1.0, 10);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn new(alpha: f64, truncation: u64) -> Result<StickBreaking> {
+        if !alpha.is_finite() || alpha <= 0.0 || truncation == 0 {
+            return Err(StatsError::BadParams);
+        }
+        Ok(StickBreaking {
+            alpha: alpha,
+            truncation: truncation,
+        })
+    }
+
+    /// Returns the concentration parameter of the process.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the truncation level used by `pmf`/`ln_pmf`.
+    pub fn truncation(&self) -> u64 {
+        self.truncation
+    }
+}
+
+// hard cap on the number of stick-breaking steps taken by `sample`, guarding
+// against `remaining` underflowing to exactly `0.0` before `acc` exceeds `u`
+// (which would otherwise stall `acc` forever); mirrors `Mixture::sample`
+// falling back to its last component when rounding leaves `u` unconsumed
+const MAX_SAMPLE_ITERATIONS: u64 = 10_000;
+
+impl Distribution<u64> for StickBreaking {
+    /// Draws a category index by lazily breaking the stick: repeatedly
+    /// drawing `Beta(1, alpha)` proportions of what remains and
+    /// accumulating weight until it exceeds a uniform draw. Returns the
+    /// current index if `remaining` reaches `0.0` first, and returns
+    /// `MAX_SAMPLE_ITERATIONS` if that many steps pass without either.
+    fn sample<R: Rng>(&self, r: &mut R) -> u64 {
+        let u = r.next_f64();
+        let beta = Beta::new(1.0, self.alpha).unwrap();
+        let mut remaining = 1.0;
+        let mut acc = 0.0;
+        let mut k = 0u64;
+        while k < MAX_SAMPLE_ITERATIONS {
+            let w = beta.sample(r) * remaining;
+            acc += w;
+            if acc > u || remaining <= 0.0 {
+                return k;
+            }
+            remaining -= w;
+            k += 1;
+        }
+        k
+    }
+}
+
+impl Discrete<u64, f64> for StickBreaking {
+    /// Returns the expected stick-breaking weight of category `x` under a
+    /// truncation at `self.truncation()` levels, where the final level
+    /// absorbs the remaining tail mass so the weights sum to `1.0`.
+    fn pmf(&self, x: u64) -> f64 {
+        if x >= self.truncation {
+            return 0.0;
+        }
+        let r = self.alpha / (1.0 + self.alpha);
+        if x == self.truncation - 1 {
+            r.powi(x as i32)
+        } else {
+            (1.0 - r) * r.powi(x as i32)
+        }
+    }
+
+    fn ln_pmf(&self, x: u64) -> f64 {
+        self.pmf(x).ln()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::StdRng;
+    use prec;
+
+    #[test]
+    fn test_new_rejects_invalid_params() {
+        assert!(StickBreaking::new(0.0, 10).is_err());
+        assert!(StickBreaking::new(-1.0, 10).is_err());
+        assert!(StickBreaking::new(1.0, 0).is_err());
+        assert!(StickBreaking::new(f64::NAN, 10).is_err());
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one_under_truncation() {
+        let sb = StickBreaking::new(2.0, 5).unwrap();
+        let total = (0..5).fold(0.0, |acc, k| acc + sb.pmf(k));
+        assert!(prec::almost_eq(total, 1.0, 1e-10));
+    }
+
+    #[test]
+    fn test_pmf_zero_beyond_truncation() {
+        let sb = StickBreaking::new(2.0, 5).unwrap();
+        assert_eq!(sb.pmf(5), 0.0);
+        assert_eq!(sb.pmf(100), 0.0);
+    }
+
+    #[test]
+    fn test_sample_terminates_below_iteration_cap() {
+        // a single draw from a randomly seeded generator terminates with an
+        // index below the cap; a `u` close to 1.0 is not forced here
+        let sb = StickBreaking::new(1.0, 10).unwrap();
+        let mut r = StdRng::new().unwrap();
+        let k = sb.sample(&mut r);
+        assert!(k < MAX_SAMPLE_ITERATIONS);
+    }
+}